@@ -1,19 +1,77 @@
+// pyo3 0.20's `#[pymethods]` expansion trips `non_local_definitions` under
+// current rustc; the lint is about the macro's own generated impl, not
+// anything in this file. See https://github.com/PyO3/pyo3/issues/3900.
+#![allow(non_local_definitions)]
+
 use pyo3::prelude::*;
-use numpy::PyArray2;
+use serde::{Deserialize, Serialize};
 
-/// Core polyform data structure in Rust
-#[derive(Debug)]
+/// Core polyform data structure in Rust. `Serialize`/`Deserialize` let it
+/// round-trip through `call_python_json` in the `polylog6_desktop` bridge the
+/// same way `SimulationParams`/`SimulationResult` do.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
 pub struct Polyform {
+    #[pyo3(get)]
     pub vertices: Vec<[f64; 3]>,
+    #[pyo3(get)]
     pub id: String,
+    #[pyo3(get)]
     pub sides: usize,
+    #[pyo3(get)]
     pub position: [f64; 3],
 }
 
+#[pymethods]
+impl Polyform {
+    /// Build a regular polyform with `sides` sides, centered on `position`.
+    #[new]
+    fn new(id: String, sides: usize, position: [f64; 3]) -> Self {
+        let vertices = regular_polygon_vertices(sides, position);
+        Polyform {
+            vertices,
+            id,
+            sides,
+            position,
+        }
+    }
+
+    /// Recompute `vertices` for a new `sides`/`position`, replacing the
+    /// polyform's geometry in place.
+    fn regenerate(&mut self, sides: usize, position: [f64; 3]) {
+        self.vertices = regular_polygon_vertices(sides, position);
+        self.sides = sides;
+        self.position = position;
+    }
+}
+
+/// Vertices of a unit regular polygon with `sides` sides in the XY plane,
+/// vertex `k` at angle `2πk/sides`, translated by `position`.
+fn regular_polygon_vertices(sides: usize, position: [f64; 3]) -> Vec<[f64; 3]> {
+    (0..sides)
+        .map(|k| {
+            let angle = std::f64::consts::TAU * (k as f64) / (sides as f64);
+            [
+                angle.cos() + position[0],
+                angle.sin() + position[1],
+                position[2],
+            ]
+        })
+        .collect()
+}
+
+/// Module-level constructor for [`Polyform`]; same result as calling
+/// `Polyform(id, sides, position)` from Python.
+#[pyfunction]
+fn make_polyform(id: String, sides: usize, position: [f64; 3]) -> Polyform {
+    Polyform::new(id, sides, position)
+}
+
 /// Python module implementation
 #[pymodule]
 fn polylog6(_py: Python, m: &PyModule) -> PyResult<()> {
-    // Register Python-facing functions here
+    m.add_class::<Polyform>()?;
+    m.add_function(wrap_pyfunction!(make_polyform, m)?)?;
     Ok(())
 }
 
@@ -23,6 +81,29 @@ mod tests {
 
     #[test]
     fn test_polyform_creation() {
-        // Test polyform creation
+        let polyform = Polyform::new("p1".to_string(), 4, [1.0, 2.0, 0.0]);
+        assert_eq!(polyform.sides, 4);
+        assert_eq!(polyform.vertices.len(), 4);
+        assert_eq!(polyform.vertices[0], [2.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_polyform_regenerate() {
+        let mut polyform = Polyform::new("p1".to_string(), 4, [0.0, 0.0, 0.0]);
+        polyform.regenerate(6, [1.0, 0.0, 0.0]);
+        assert_eq!(polyform.sides, 6);
+        assert_eq!(polyform.position, [1.0, 0.0, 0.0]);
+        assert_eq!(polyform.vertices.len(), 6);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_polyform_json_round_trip() {
+        let polyform = Polyform::new("p1".to_string(), 4, [1.0, 2.0, 0.0]);
+        let json = serde_json::to_string(&polyform).unwrap();
+        let back: Polyform = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.id, polyform.id);
+        assert_eq!(back.sides, polyform.sides);
+        assert_eq!(back.position, polyform.position);
+        assert_eq!(back.vertices, polyform.vertices);
+    }
+}
@@ -0,0 +1,57 @@
+//! `#[tauri_command]` wraps a fn in `#[tauri::command]` and, in the same
+//! expansion, submits its real argument names/types and return type into the
+//! `CommandBinding` inventory that `bindings::generate_typescript` walks.
+//! Because the binding is read off the function's own `syn::ItemFn` instead
+//! of being retyped by hand in a separate file, a command's TypeScript
+//! wrapper can never drift from its Rust signature.
+
+use proc_macro::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, FnArg, ItemFn, Pat, ReturnType};
+
+#[proc_macro_attribute]
+pub fn tauri_command(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemFn);
+    let fn_name = &input.sig.ident;
+    let fn_name_str = fn_name.to_string();
+
+    let arg_entries: Vec<_> = input
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| {
+            let FnArg::Typed(pat_ty) = arg else {
+                return None;
+            };
+            let Pat::Ident(pat_ident) = pat_ty.pat.as_ref() else {
+                return None;
+            };
+            let name = pat_ident.ident.to_string();
+            let ty_str = pat_ty.ty.to_token_stream().to_string();
+            Some(quote! { (#name, crate::bindings::rust_type_to_ts(#ty_str)) })
+        })
+        .collect();
+
+    let return_ts = match &input.sig.output {
+        ReturnType::Default => quote! { "void".to_string() },
+        ReturnType::Type(_, ty) => {
+            let ty_str = ty.to_token_stream().to_string();
+            quote! { crate::bindings::rust_return_to_ts(#ty_str) }
+        }
+    };
+
+    let expanded = quote! {
+        #[::tauri::command]
+        #input
+
+        ::inventory::submit! {
+            crate::bindings::CommandBinding {
+                name: #fn_name_str,
+                args: || vec![#(#arg_entries),*],
+                return_type: || #return_ts,
+            }
+        }
+    };
+
+    expanded.into()
+}
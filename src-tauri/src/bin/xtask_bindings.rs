@@ -0,0 +1,12 @@
+//! Writes the generated TypeScript command wrappers to the frontend.
+//! Run with `cargo run --bin xtask_bindings` whenever a `#[command]` is
+//! added, removed, or re-typed in `commands.rs`.
+
+use polylog6_desktop::bindings::generate_typescript;
+
+fn main() {
+    let out_path = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/desktop/bindings.ts");
+    std::fs::write(out_path, generate_typescript())
+        .unwrap_or_else(|e| panic!("failed to write generated bindings to {out_path}: {e}"));
+    println!("wrote bindings to {out_path}");
+}
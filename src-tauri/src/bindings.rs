@@ -0,0 +1,206 @@
+//! `CommandBinding` inventory consumed by [`generate_typescript`]. Entries
+//! are never written by hand here — each one is submitted by the
+//! `#[tauri_command]` attribute macro (see `macros/src/lib.rs`) straight off
+//! the real `#[command]` fn's signature, so adding, removing, or re-typing a
+//! command in `commands.rs` automatically updates the generated TypeScript,
+//! with no separate file to keep in sync.
+
+use std::fmt::Write as _;
+
+/// A Tauri command's name plus its argument names/TypeScript types and
+/// return TypeScript type, submitted by `#[tauri_command]`.
+pub struct CommandBinding {
+    pub name: &'static str,
+    pub args: fn() -> Vec<(&'static str, String)>,
+    pub return_type: fn() -> String,
+}
+
+inventory::collect!(CommandBinding);
+
+/// Map a Rust type's token spelling (captured verbatim by `#[tauri_command]`
+/// from the real function signature) to its TypeScript equivalent. Unknown
+/// identifiers pass through unchanged, which is correct for request/response
+/// structs (`SimulationParams`, `SimulationResult`, ...) as long as their
+/// hand-written TypeScript interfaces share the same name.
+pub fn rust_type_to_ts(rust_ty: &str) -> String {
+    let compact: String = rust_ty.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if let Some(inner) = strip_wrapper(&compact, "Vec<", ">") {
+        let elem_ts = rust_type_to_ts(&inner);
+        let elem_ts = if elem_ts.contains('|') {
+            format!("({elem_ts})")
+        } else {
+            elem_ts
+        };
+        return format!("{elem_ts}[]");
+    }
+    if let Some((ok_ty, err_ty)) = split_generic(&compact, "Result<") {
+        return format!(
+            "{{ Ok: {} }} | {{ Err: {} }}",
+            rust_type_to_ts(&ok_ty),
+            rust_type_to_ts(&err_ty)
+        );
+    }
+    if let Some(inner) = compact.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        if let Some((elem, count)) = inner.split_once(';') {
+            let n: usize = count.parse().unwrap_or(0);
+            let elem_ts = rust_type_to_ts(elem);
+            return format!("[{}]", vec![elem_ts; n].join(", "));
+        }
+    }
+
+    match compact.as_str() {
+        "String" | "&str" | "&'staticstr" => "string".to_string(),
+        "usize" | "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "f32" | "f64" => {
+            "number".to_string()
+        }
+        "bool" => "boolean".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Resolve a function's return-type tokens to the TS type its Tauri-wrapped
+/// promise resolves to: `Result<T, String>` resolves with `T` and rejects
+/// with the (string) error, so only `T` needs a TS type here. Anything else
+/// (e.g. `Vec<Result<T, String>>`) is mapped structurally like any other type.
+pub fn rust_return_to_ts(raw: &str) -> String {
+    let compact: String = raw.chars().filter(|c| !c.is_whitespace()).collect();
+    if let Some((ok_ty, _err_ty)) = split_generic(&compact, "Result<") {
+        return rust_type_to_ts(&ok_ty);
+    }
+    rust_type_to_ts(&compact)
+}
+
+/// Tauri v1 commands default to `rename_all = "camelCase"` for their
+/// argument keys (`ArgumentCase::Camel`), and `#[tauri_command]` never
+/// overrides that, so the JS side of `invoke` must send camelCased keys
+/// even though the Rust parameter names are snake_case.
+fn to_camel_case(rust_name: &str) -> String {
+    let mut out = String::with_capacity(rust_name.len());
+    let mut upper_next = false;
+    for c in rust_name.chars() {
+        if c == '_' {
+            upper_next = true;
+        } else if upper_next {
+            out.extend(c.to_uppercase());
+            upper_next = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn strip_wrapper(compact: &str, prefix: &str, suffix: &str) -> Option<String> {
+    compact
+        .strip_prefix(prefix)
+        .and_then(|s| s.strip_suffix(suffix))
+        .map(|s| s.to_string())
+}
+
+/// Split `Name<A,B>` into `(A, B)` at the first comma outside any nested
+/// `<...>`. Commas inside tuple `(...)` or array `[...]` types are not skipped.
+fn split_generic(compact: &str, prefix: &str) -> Option<(String, String)> {
+    let inner = compact.strip_prefix(prefix)?.strip_suffix('>')?;
+    let mut depth = 0i32;
+    let comma = inner
+        .char_indices()
+        .find(|&(_, c)| match c {
+            '<' => {
+                depth += 1;
+                false
+            }
+            '>' => {
+                depth -= 1;
+                false
+            }
+            ',' => depth == 0,
+            _ => false,
+        })?
+        .0;
+    Some((inner[..comma].to_string(), inner[comma + 1..].to_string()))
+}
+
+/// Render every registered [`CommandBinding`] as a typed wrapper around
+/// Tauri's `invoke`, plus the shared request/response interfaces.
+pub fn generate_typescript() -> String {
+    let mut out = String::new();
+    out.push_str("// @generated by `cargo run --bin xtask_bindings`. Do not edit by hand.\n");
+    out.push_str("import { invoke } from '@tauri-apps/api/tauri';\n\n");
+    out.push_str("export interface SimulationParams {\n  sides: number;\n  position: [number, number, number];\n}\n\n");
+    out.push_str("export interface SimulationResult {\n  id: string;\n  vertices: [number, number, number][];\n}\n\n");
+
+    for binding in inventory::iter::<CommandBinding> {
+        let args = (binding.args)();
+        let args_sig = args
+            .iter()
+            .map(|(name, ty)| format!("{}: {ty}", to_camel_case(name)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let args_call = args
+            .iter()
+            .map(|(name, _)| to_camel_case(name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let return_ty = (binding.return_type)();
+        let _ = writeln!(
+            out,
+            "export function {name}({args_sig}): Promise<{return_ty}> {{\n  return invoke('{name}', {{ {args_call} }});\n}}\n",
+            name = binding.name,
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_primitive_and_string_types() {
+        assert_eq!(rust_type_to_ts("String"), "string");
+        assert_eq!(rust_type_to_ts("usize"), "number");
+        assert_eq!(rust_type_to_ts("bool"), "boolean");
+    }
+
+    #[test]
+    fn maps_vec_and_array_types() {
+        assert_eq!(rust_type_to_ts("Vec<String>"), "string[]");
+        assert_eq!(rust_type_to_ts("[f64;3]"), "[number, number, number]");
+    }
+
+    #[test]
+    fn maps_result_types_to_a_tagged_union() {
+        assert_eq!(
+            rust_type_to_ts("Result<String,String>"),
+            "{ Ok: string } | { Err: string }"
+        );
+    }
+
+    #[test]
+    fn return_type_unwraps_the_result_ok_arm() {
+        assert_eq!(rust_return_to_ts("Result<String,String>"), "string");
+        assert_eq!(rust_return_to_ts("Vec<String>"), "string[]");
+    }
+
+    #[test]
+    fn passes_through_unknown_custom_types() {
+        assert_eq!(rust_type_to_ts("SimulationParams"), "SimulationParams");
+    }
+
+    #[test]
+    fn camel_cases_snake_case_names() {
+        assert_eq!(to_camel_case("params"), "params");
+        assert_eq!(to_camel_case("raw_params"), "rawParams");
+        assert_eq!(to_camel_case("a_b_c"), "aBC");
+    }
+
+    #[test]
+    fn generated_typescript_includes_every_registered_command() {
+        let ts = generate_typescript();
+        assert!(ts.contains("export function run_simulation("));
+        assert!(ts.contains("export function run_simulation_batch("));
+        assert!(ts.contains("invoke('run_simulation', { params })"));
+    }
+}
@@ -0,0 +1,118 @@
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use polylog6_desktop_macros::tauri_command;
+
+use crate::python_bridge::{bridge_error_to_json, call_python_json, call_python_module};
+
+/// Parameters for a polyform simulation run.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulationParams {
+    pub sides: usize,
+    pub position: [f64; 3],
+}
+
+/// Result of a polyform simulation run.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub id: String,
+    pub vertices: Vec<[f64; 3]>,
+}
+
+#[tauri_command]
+pub fn run_simulation(params: SimulationParams) -> Result<SimulationResult, String> {
+    call_python_json("polylog_core", "simulate", &params).map_err(|e| bridge_error_to_json(&e))
+}
+
+#[tauri_command]
+pub fn get_unicode_symbols(params: String) -> Result<String, String> {
+    call_python_module("polylog_core", "get_unicode_symbols", &params)
+        .map_err(|e| bridge_error_to_json(&e))
+}
+
+#[tauri_command]
+pub fn launch_polyform(params: String) -> Result<String, String> {
+    call_python_module("polylog_core", "launch_polyform", &params)
+        .map_err(|e| bridge_error_to_json(&e))
+}
+
+/// Run a batch of simulations/polyform generations across a `rayon` thread
+/// pool. Each item reports its own success or failure instead of one bad
+/// item failing the whole batch.
+#[tauri_command]
+pub fn run_simulation_batch(params: Vec<String>) -> Vec<Result<String, String>> {
+    params.into_par_iter().map(run_simulation_batch_item).collect()
+}
+
+/// Rust-side prep for one batch item — decoding and re-serializing its
+/// `SimulationParams` — is plain Rust with no Python involved, so it runs
+/// before the GIL is ever acquired; [`Python::with_gil`] (inside
+/// `call_python_module`) is only entered for the actual call into
+/// `polylog_core`.
+fn run_simulation_batch_item(raw_params: String) -> Result<String, String> {
+    let parsed: SimulationParams =
+        serde_json::from_str(&raw_params).map_err(|e| e.to_string())?;
+    let prepared = serde_json::to_string(&parsed).map_err(|e| e.to_string())?;
+    call_python_module("polylog_core", "simulate", &prepared).map_err(|e| bridge_error_to_json(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// No real `polylog_core` Python module is installed in the test
+    /// environment, so every command here deterministically fails to import
+    /// it — these tests exercise the command's plumbing (GIL-free JSON prep,
+    /// per-item batch isolation, `BridgeError` surfaced as JSON) rather than
+    /// the Python-side simulation itself.
+    fn assert_module_not_found(err_json: &str) {
+        assert!(
+            err_json.contains(r#""kind":"ModuleNotFound""#),
+            "expected a ModuleNotFound BridgeError, got: {err_json}"
+        );
+    }
+
+    #[test]
+    fn run_simulation_reports_missing_module() {
+        let params = SimulationParams {
+            sides: 4,
+            position: [0.0, 0.0, 0.0],
+        };
+        let err = run_simulation(params).unwrap_err();
+        assert_module_not_found(&err);
+    }
+
+    #[test]
+    fn get_unicode_symbols_reports_missing_module() {
+        let err = get_unicode_symbols("ignored".to_string()).unwrap_err();
+        assert_module_not_found(&err);
+    }
+
+    #[test]
+    fn launch_polyform_reports_missing_module() {
+        let err = launch_polyform("ignored".to_string()).unwrap_err();
+        assert_module_not_found(&err);
+    }
+
+    #[test]
+    fn run_simulation_batch_item_rejects_invalid_json_before_touching_python() {
+        let err = run_simulation_batch_item("not json".to_string()).unwrap_err();
+        assert!(
+            !err.contains("ModuleNotFound"),
+            "invalid JSON should fail in the plain-Rust decode step, not reach call_python_module: {err}"
+        );
+    }
+
+    #[test]
+    fn run_simulation_batch_reports_one_result_per_item_independently() {
+        let items = vec![
+            r#"{"sides":4,"position":[0.0,0.0,0.0]}"#.to_string(),
+            "not json".to_string(),
+            r#"{"sides":6,"position":[1.0,0.0,0.0]}"#.to_string(),
+        ];
+        let results = run_simulation_batch(items);
+        assert_eq!(results.len(), 3);
+        assert_module_not_found(&results[0].as_ref().unwrap_err().clone());
+        assert!(!results[1].as_ref().unwrap_err().contains("ModuleNotFound"));
+        assert_module_not_found(&results[2].as_ref().unwrap_err().clone());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod bindings;
+pub mod commands;
+pub mod python_bridge;
@@ -1,13 +1,12 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod commands;
-
 fn main() {
     tauri::Builder::default()
         .invoke_handler(tauri::generate_handler![
-            commands::run_simulation,
-            commands::get_unicode_symbols,
-            commands::launch_polyform
+            polylog6_desktop::commands::run_simulation,
+            polylog6_desktop::commands::get_unicode_symbols,
+            polylog6_desktop::commands::launch_polyform,
+            polylog6_desktop::commands::run_simulation_batch
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
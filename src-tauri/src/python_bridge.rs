@@ -0,0 +1,292 @@
+use std::fmt;
+
+use pyo3::exceptions::{PyAttributeError, PyImportError};
+use pyo3::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Errors that can occur while crossing the Python/Rust bridge. Replaces the
+/// flat `e.to_string()` failures that used to reach the frontend, so the UI
+/// can branch on error kind and show a traceback instead of a bare message.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind")]
+pub enum BridgeError {
+    /// Importing `module_name` raised `ImportError` (e.g. it doesn't exist).
+    ModuleNotFound { module: String },
+    /// `module_name` exists but has no `function_name` attribute.
+    AttributeMissing { module: String, attribute: String },
+    /// Python code raised an exception or deliberately returned a
+    /// `PyErrorPayload`. A return value that is neither a string nor such a
+    /// payload is also reported here, as the `TypeError` of the failed
+    /// string extraction.
+    PythonRaised {
+        exc_type: String,
+        message: String,
+        traceback: String,
+    },
+    /// The JSON payload could not be serialized or deserialized.
+    Conversion { message: String },
+}
+
+impl fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BridgeError::ModuleNotFound { module } => write!(f, "module not found: {module}"),
+            BridgeError::AttributeMissing { module, attribute } => {
+                write!(f, "{module} has no attribute '{attribute}'")
+            }
+            BridgeError::PythonRaised {
+                exc_type, message, ..
+            } => write!(f, "{exc_type}: {message}"),
+            BridgeError::Conversion { message } => write!(f, "conversion error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for BridgeError {}
+
+impl From<serde_json::Error> for BridgeError {
+    fn from(err: serde_json::Error) -> Self {
+        BridgeError::Conversion {
+            message: err.to_string(),
+        }
+    }
+}
+
+/// A structured error payload a Python function can deliberately return
+/// (as opposed to raising), e.g. `{"exc_type": "ValidationError", "message": "..."}`,
+/// so Python code can signal a typed failure without going through an
+/// exception.
+#[derive(Debug)]
+pub struct PyErrorPayload {
+    pub exc_type: String,
+    pub message: String,
+    pub traceback: String,
+}
+
+impl<'source> FromPyObject<'source> for PyErrorPayload {
+    /// `#[derive(FromPyObject)]` has no way to default a missing `item`, so
+    /// `traceback` is extracted by hand and defaulted to `""` when absent.
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        let exc_type: String = ob.get_item("exc_type")?.extract()?;
+        let message: String = ob.get_item("message")?.extract()?;
+        let traceback = ob
+            .get_item("traceback")
+            .and_then(|v| v.extract())
+            .unwrap_or_default();
+        Ok(PyErrorPayload {
+            exc_type,
+            message,
+            traceback,
+        })
+    }
+}
+
+impl From<PyErrorPayload> for BridgeError {
+    fn from(payload: PyErrorPayload) -> Self {
+        BridgeError::PythonRaised {
+            exc_type: payload.exc_type,
+            message: payload.message,
+            traceback: payload.traceback,
+        }
+    }
+}
+
+/// Serialize a [`BridgeError`] to a JSON string, falling back to its
+/// `Display` text if serialization itself somehow fails. Intended for the
+/// Tauri command boundary, where commands return `Result<T, String>`.
+pub fn bridge_error_to_json(err: &BridgeError) -> String {
+    serde_json::to_string(err).unwrap_or_else(|_| err.to_string())
+}
+
+fn module_error(module_name: &str, err: PyErr, py: Python<'_>) -> BridgeError {
+    if err.is_instance_of::<PyImportError>(py) {
+        BridgeError::ModuleNotFound {
+            module: module_name.to_string(),
+        }
+    } else {
+        python_raised(err, py)
+    }
+}
+
+fn attribute_error(module_name: &str, function_name: &str, err: PyErr, py: Python<'_>) -> BridgeError {
+    if err.is_instance_of::<PyAttributeError>(py) {
+        BridgeError::AttributeMissing {
+            module: module_name.to_string(),
+            attribute: function_name.to_string(),
+        }
+    } else {
+        python_raised(err, py)
+    }
+}
+
+fn python_raised(err: PyErr, py: Python<'_>) -> BridgeError {
+    // `err.value(py)` is the exception *instance*, not whatever was passed to
+    // its constructor, so there's no reliable way to recover a structured
+    // `PyErrorPayload` from a raised exception here. Python code that wants a
+    // structured error should *return* a `PyErrorPayload`-shaped dict instead
+    // of raising — see the extract fallback in `call_python_module`.
+    let exc_type = err
+        .get_type(py)
+        .name()
+        .map(|name| name.to_string())
+        .unwrap_or_else(|_| "Exception".to_string());
+    let message = err.value(py).to_string();
+    let traceback = err
+        .traceback(py)
+        .and_then(|tb| tb.format().ok())
+        .unwrap_or_default();
+    BridgeError::PythonRaised {
+        exc_type,
+        message,
+        traceback,
+    }
+}
+
+/// Call a Python function with a single string argument and extract a string
+/// return value. This is the string-based entry point kept for callers that
+/// haven't moved to typed payloads; [`call_python_json`] is built on top of it.
+pub fn call_python_module(
+    module_name: &str,
+    function_name: &str,
+    args: &str,
+) -> Result<String, BridgeError> {
+    Python::with_gil(|py| {
+        let module =
+            PyModule::import(py, module_name).map_err(|e| module_error(module_name, e, py))?;
+        let callable = module
+            .getattr(function_name)
+            .map_err(|e| attribute_error(module_name, function_name, e, py))?;
+        let value = callable
+            .call1((args,))
+            .map_err(|e| python_raised(e, py))?;
+        // A function can also *return* a `PyErrorPayload`-shaped dict rather
+        // than raising, to signal failure without an exception.
+        match value.extract::<String>() {
+            Ok(s) => Ok(s),
+            Err(extract_err) => match value.extract::<PyErrorPayload>() {
+                Ok(payload) => Err(BridgeError::from(payload)),
+                Err(_) => Err(python_raised(extract_err, py)),
+            },
+        }
+    })
+}
+
+/// Call a Python function, serializing `input` to JSON on the way in and
+/// deserializing the return value back into `O` on the way out, so callers
+/// get a typed round trip instead of shuffling opaque strings across the
+/// boundary.
+pub fn call_python_json<I, O>(
+    module_name: &str,
+    function_name: &str,
+    input: &I,
+) -> Result<O, BridgeError>
+where
+    I: Serialize,
+    O: DeserializeOwned,
+{
+    let payload = serde_json::to_string(input)?;
+    let result = call_python_module(module_name, function_name, &payload)?;
+    Ok(serde_json::from_str(&result)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::{PyDict, PyModule};
+
+    #[test]
+    fn bridge_error_serializes_with_tag() {
+        let err = BridgeError::ModuleNotFound {
+            module: "polylog_core".to_string(),
+        };
+        let json = bridge_error_to_json(&err);
+        assert_eq!(
+            json,
+            r#"{"kind":"ModuleNotFound","module":"polylog_core"}"#
+        );
+    }
+
+    #[test]
+    fn python_raised_serializes_traceback() {
+        let err = BridgeError::PythonRaised {
+            exc_type: "ValueError".to_string(),
+            message: "bad input".to_string(),
+            traceback: "Traceback...".to_string(),
+        };
+        let json = bridge_error_to_json(&err);
+        assert_eq!(
+            json,
+            r#"{"kind":"PythonRaised","exc_type":"ValueError","message":"bad input","traceback":"Traceback..."}"#
+        );
+    }
+
+    #[test]
+    fn py_error_payload_defaults_missing_traceback() {
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("exc_type", "ValidationError").unwrap();
+            dict.set_item("message", "missing field").unwrap();
+            let payload: PyErrorPayload = dict.extract().unwrap();
+            assert_eq!(payload.exc_type, "ValidationError");
+            assert_eq!(payload.message, "missing field");
+            assert_eq!(payload.traceback, "");
+        });
+    }
+
+    #[test]
+    fn call_python_json_round_trips() {
+        Python::with_gil(|py| {
+            let module = PyModule::from_code(
+                py,
+                "def echo(payload):\n    return payload\n",
+                "echo_mod.py",
+                "echo_mod",
+            )
+            .unwrap();
+            py.import("sys")
+                .unwrap()
+                .getattr("modules")
+                .unwrap()
+                .set_item("echo_mod", module)
+                .unwrap();
+        });
+
+        #[derive(Debug, Serialize, serde::Deserialize, PartialEq)]
+        struct Ping {
+            n: u32,
+        }
+
+        let result: Ping = call_python_json("echo_mod", "echo", &Ping { n: 7 }).unwrap();
+        assert_eq!(result, Ping { n: 7 });
+    }
+
+    #[test]
+    fn call_python_json_round_trips_polyform() {
+        Python::with_gil(|py| {
+            let module = PyModule::from_code(
+                py,
+                "def echo(payload):\n    return payload\n",
+                "echo_polyform_mod.py",
+                "echo_polyform_mod",
+            )
+            .unwrap();
+            py.import("sys")
+                .unwrap()
+                .getattr("modules")
+                .unwrap()
+                .set_item("echo_polyform_mod", module)
+                .unwrap();
+        });
+
+        let polyform = polylog_core::Polyform {
+            vertices: vec![[2.0, 2.0, 0.0], [1.0, 3.0, 0.0]],
+            id: "p1".to_string(),
+            sides: 2,
+            position: [1.0, 2.0, 0.0],
+        };
+        let result: polylog_core::Polyform =
+            call_python_json("echo_polyform_mod", "echo", &polyform).unwrap();
+        assert_eq!(result, polyform);
+    }
+}